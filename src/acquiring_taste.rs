@@ -158,7 +158,7 @@ async fn resolve_pick(
             pick_from_track_id(spotify, &pick.submitter, id).await
         }
         (Some("spotify.link"), [_]) => {
-            eprintln!("Found shortened link, resolving it");
+            tracing::debug!("Found shortened link, resolving it");
             pick_from_shortened_link(spotify, &pick.submitter, &pick.link).await
         }
         _ => return Err((pick, anyhow!("Not a spotify URL"))),
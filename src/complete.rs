@@ -50,7 +50,7 @@ async fn autocomplete_link(
         match get_now_playing(handler, user_id).await {
             Ok(np) => return np.into_iter().collect(),
             Err(e) => {
-                eprintln!("Error getting user's current track: {e}")
+                tracing::warn!("Error getting user's current track: {e}")
             }
         }
     }
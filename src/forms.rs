@@ -1,4 +1,8 @@
-use std::{cmp::Ordering, sync::Arc, time::Duration};
+use std::{
+    cmp::Ordering,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use anyhow::{anyhow, bail, Context as _};
 use fallible_iterator::FallibleIterator;
@@ -402,6 +406,9 @@ impl BotCommand for CommandFromForm {
     }
 }
 
+// Compiled once on first use rather than on every call, since building a `Regex` is expensive.
+static FORM_URL_RE: OnceLock<Regex> = OnceLock::new();
+
 impl CommandFromForm {
     async fn add_form(
         mut self,
@@ -409,8 +416,9 @@ impl CommandFromForm {
         ctx: &Context,
         guild_id: GuildId,
     ) -> anyhow::Result<CommandResponse> {
-        let spreadsheet_url_re = Regex::new(r#"https://docs.google.com/forms/d/([^/]+)"#).unwrap();
-        if let Some(cap) = spreadsheet_url_re.captures(&self.form_id) {
+        let form_url_re = FORM_URL_RE
+            .get_or_init(|| Regex::new(r#"https://docs.google.com/forms/d/([^/]+)"#).unwrap());
+        if let Some(cap) = form_url_re.captures(&self.form_id) {
             self.form_id = cap.get(1).unwrap().as_str().to_string();
         }
         let forms: &Forms = handler.module()?;
@@ -14,6 +14,8 @@ use serenity::prelude::{Context, EventHandler};
 use serenity::{
     model::application::CommandDataOption, model::channel::Message, prelude::GatewayIntents,
 };
+use tracing::{error, info};
+use tracing_subscriber::EnvFilter;
 // use youtube::Youtube;
 
 use serenity_command_handler::Handler;
@@ -67,7 +69,7 @@ impl EventHandler for HandlerWrapper {
             }
         }
         self.0.self_id.set(data_about_bot.user.id).unwrap();
-        eprintln!("{} is running!", &data_about_bot.user.name);
+        info!("{} is running!", &data_about_bot.user.name);
         for runner in self.0.commands.read().await.0.values() {
             if let Some(guild) = runner.guild() {
                 guild
@@ -139,7 +141,10 @@ impl EventHandler for HandlerWrapper {
                 .name(&ctx.cache)
                 .map(|name| format!("[{name}] "))
                 .unwrap_or_default();
-            eprintln!("{guild_name}Error moving message to pinboard: {e:?}");
+            error!(
+                guild_id = guild_id.get(),
+                "{guild_name}Error moving message to pinboard: {e:?}"
+            );
         }
     }
 }
@@ -183,6 +188,12 @@ async fn build_handler() -> anyhow::Result<Handler> {
 
 #[tokio::main]
 async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
     let handler = build_handler().await.unwrap();
 
     let token = env::var("DISCORD_TOKEN").expect("Expected a token in the environment");
@@ -211,6 +222,6 @@ async fn main() {
     // Shards will automatically attempt to reconnect, and will perform
     // exponential backoff until it reconnects.
     if let Err(why) = client.start().await {
-        println!("Client error: {:?}", why);
+        error!("Client error: {:?}", why);
     }
 }